@@ -0,0 +1,149 @@
+//! Module defining the [`Device`] handle and the [`Devices`] iterator
+//! returned by [`Rsmi::devices`].
+
+use std::ffi::CStr;
+
+use crate::error::{RsmiError, rsmi_sym, rsmi_try};
+use crate::Rsmi;
+
+
+/// Borrowed handle to a single GPU device, obtained through [`Rsmi::devices`].
+///
+/// Every per-device getter that used to live directly on [`Rsmi`] (and take
+/// a raw `device_index: u32`) now lives here instead, so callers can no
+/// longer pass an out-of-range index by hand: a `Device` can only be
+/// obtained for an index that [`Rsmi::devices`] knows about.
+#[derive(Clone, Copy)]
+pub struct Device<'a> {
+    rsmi: &'a Rsmi,
+    index: u32,
+}
+
+impl<'a> Device<'a> {
+
+    pub(crate) fn new(rsmi: &'a Rsmi, index: u32) -> Self {
+        Device { rsmi, index }
+    }
+
+    /// The monitor index this handle refers to.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The [`Rsmi`] instance this handle was obtained from.
+    pub(crate) fn rsmi(&self) -> &'a Rsmi {
+        self.rsmi
+    }
+
+    /// Get the device id associated with this device.
+    #[doc(alias = "rsmi_dev_id_get")]
+    pub fn id(&self) -> Result<u16, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi.lib.rsmi_dev_id_get, "rsmi_dev_id_get")?;
+        let mut id = 0;
+        unsafe {
+            rsmi_try(&self.rsmi.lib, sym(self.index, &mut id), "rsmi_dev_id_get")?;
+        }
+        Ok(id)
+    }
+
+    /// Get the SKU for this device.
+    #[doc(alias = "rsmi_dev_sku_get")]
+    pub fn sku(&self) -> Result<i8, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi.lib.rsmi_dev_sku_get, "rsmi_dev_sku_get")?;
+        let mut sku = 0;
+        unsafe {
+            rsmi_try(&self.rsmi.lib, sym(self.index, &mut sku), "rsmi_dev_sku_get")?;
+        }
+        Ok(sku)
+    }
+
+    /// Get the vendor id of this device.
+    #[doc(alias = "rsmi_dev_vendor_id_get")]
+    pub fn vendor_id(&self) -> Result<u16, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi.lib.rsmi_dev_vendor_id_get, "rsmi_dev_vendor_id_get")?;
+        let mut vendor_id = 0;
+        unsafe {
+            rsmi_try(&self.rsmi.lib, sym(self.index, &mut vendor_id), "rsmi_dev_vendor_id_get")?;
+        }
+        Ok(vendor_id)
+    }
+
+    /// Get the unique PCI device identifier of this device, encoding its
+    /// domain, bus, device and function numbers (a "BDFID").
+    #[doc(alias = "rsmi_dev_pci_id_get")]
+    pub fn pci_id(&self) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi.lib.rsmi_dev_pci_id_get, "rsmi_dev_pci_id_get")?;
+        let mut bdfid = 0;
+        unsafe {
+            rsmi_try(&self.rsmi.lib, sym(self.index, &mut bdfid), "rsmi_dev_pci_id_get")?;
+        }
+        Ok(bdfid)
+    }
+
+    fn get_string<S: From<u16>>(&self, sym: unsafe extern "C" fn(u32, *mut i8, S) -> u32, name: &'static str) -> Result<String, RsmiError> {
+        const BUFFER_LEN: u16 = 256;
+        let mut buffer = [0i8; BUFFER_LEN as usize];
+        unsafe {
+            rsmi_try(&self.rsmi.lib, sym(self.index, buffer.as_mut_ptr(), BUFFER_LEN.into()), name)?;
+            CStr::from_ptr(buffer.as_mut_ptr()).to_str().map_err(|_| RsmiError::InvalidUtf8).map(str::to_string)
+        }
+    }
+
+    /// Get the name string of this device.
+    #[doc(alias = "rsmi_dev_name_get")]
+    pub fn name(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_name_get, "rsmi_dev_name_get")?, "rsmi_dev_name_get")
+    }
+
+    /// Get the brand string of this device.
+    #[doc(alias = "rsmi_dev_brand_get")]
+    pub fn brand(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_brand_get, "rsmi_dev_brand_get")?, "rsmi_dev_brand_get")
+    }
+
+    /// Get the name string for this device's vendor.
+    #[doc(alias = "rsmi_dev_vendor_name_get")]
+    pub fn vendor_name(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_vendor_name_get, "rsmi_dev_vendor_name_get")?, "rsmi_dev_vendor_name_get")
+    }
+
+    /// Get the vram vendor string of this device.
+    #[doc(alias = "rsmi_dev_vram_vendor_get")]
+    pub fn vram_vendor_name(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_vram_vendor_get, "rsmi_dev_vram_vendor_get")?, "rsmi_dev_vram_vendor_get")
+    }
+
+    /// Get the serial number string of this device.
+    #[doc(alias = "rsmi_dev_serial_number_get")]
+    pub fn serial_number(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_serial_number_get, "rsmi_dev_serial_number_get")?, "rsmi_dev_serial_number_get")
+    }
+
+    /// Get the subsystem name string of this device.
+    #[doc(alias = "rsmi_dev_subsystem_name_get")]
+    pub fn subsystem_name(&self) -> Result<String, RsmiError> {
+        self.get_string(rsmi_sym(&self.rsmi.lib.rsmi_dev_subsystem_name_get, "rsmi_dev_subsystem_name_get")?, "rsmi_dev_subsystem_name_get")
+    }
+}
+
+
+/// Iterator over all devices that have monitor information, returned by
+/// [`Rsmi::devices`].
+pub struct Devices<'a> {
+    pub(crate) rsmi: &'a Rsmi,
+    pub(crate) index: u32,
+    pub(crate) count: u32,
+}
+
+impl<'a> Iterator for Devices<'a> {
+    type Item = Device<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let device = Device::new(self.rsmi, self.index);
+        self.index += 1;
+        Some(device)
+    }
+}
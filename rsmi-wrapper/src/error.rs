@@ -1,13 +1,17 @@
 //! Module for error types definition.
 
-use std::fmt::Display;
+use std::ffi::CStr;
+use std::fmt::{self, Display};
 
 use rsmi_wrapper_sys::*;
 
 
+#[derive(Debug)]
 pub enum RsmiError {
     LibloadingError(libloading::Error),
-    FailedToLoadSymbol(String),
+    FailedToLoadSymbol { name: &'static str, message: String },
+    #[cfg(feature = "hotplug")]
+    HotplugError(std::io::Error),
     InvalidUtf8,
     InvalidArgs,
     NotSupported,
@@ -27,35 +31,65 @@ pub enum RsmiError {
     Busy,
     RefcountOverflow,
     Unknown,
-    Unexpected(rsmi_status_t),
+    Unexpected { name: &'static str, code: rsmi_status_t, message: Option<String> },
 }
 
 impl Display for RsmiError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use RsmiError::*;
         match self {
             LibloadingError(err) => write!(f, "a libloading error occurred: {err}"),
+            FailedToLoadSymbol { name, message } => write!(f, "failed to load ROCm SMI symbol `{name}`: {message}"),
+            #[cfg(feature = "hotplug")]
+            HotplugError(err) => write!(f, "a udev hotplug monitoring error occurred: {err}"),
             InvalidUtf8 => write!(f, "a function returned invalid utf8 encoding and the wrapper cannot convert it"),
             InvalidArgs => write!(f, "passed in arguments are not valid"),
-            NotSupported => write!(f, "the requested information or action is not available 
-            for the given input, on the given system"),
-            File => write!(f, "problem accessing a file, this may because the operation 
-            is not supported by the linux kernel version running on the executing machine"),
-            Permission => write!(f, "permission denied/EACCESS file error, many functions
-            require root access to run"),
-            _ => todo!()
+            NotSupported => write!(f, "the requested information or action is not available for the given input, on the given system"),
+            File => write!(f, "problem accessing a file, this may because the operation is not supported by the linux kernel version running on the executing machine"),
+            Permission => write!(f, "permission denied/EACCESS file error, many functions require root access to run"),
+            OutOfResources => write!(f, "unable to acquire memory or another resource needed to complete the requested action"),
+            Internal => write!(f, "an internal exception was caught while handling the request"),
+            InputOutOfBounds => write!(f, "provided input is out of allowable or safe range"),
+            Init => write!(f, "an error occurred while initializing ROCm SMI"),
+            NotYetImplemented => write!(f, "the requested function has not yet been implemented for the current system or devices"),
+            NotFound => write!(f, "an item, such as a device or sensor, was searched for but not found"),
+            InsufficientSize => write!(f, "an output buffer passed to a function was not large enough for the result"),
+            Interrupt => write!(f, "the call was interrupted during execution and did not complete"),
+            UnexpectedSize => write!(f, "an unexpected amount of data was read from the kernel or ROCm SMI"),
+            NoData => write!(f, "no data is available for the given input"),
+            UnexpectedData => write!(f, "the data read back from the kernel or ROCm SMI is not what was expected"),
+            Busy => write!(f, "a resource required to complete the request is currently busy"),
+            RefcountOverflow => write!(f, "an internal reference counter would have overflowed"),
+            Unknown => write!(f, "an unknown error occurred"),
+            Unexpected { name, code, message: Some(message) } => write!(f, "`{name}` returned unexpected status {code} ({message})"),
+            Unexpected { name, code, message: None } => write!(f, "`{name}` returned unexpected status {code}"),
         }
 
     }
 }
 
+impl std::error::Error for RsmiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RsmiError::LibloadingError(err) => Some(err),
+            #[cfg(feature = "hotplug")]
+            RsmiError::HotplugError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 impl From<libloading::Error> for RsmiError {
     fn from(err: libloading::Error) -> Self {
         Self::LibloadingError(err)
     }
 }
 
-pub fn rsmi_try(code: rsmi_status_t) -> Result<(), RsmiError> {
+/// Check a raw `rsmi_status_t` returned by `name`, turning it into a
+/// `Result` and, for a status this wrapper doesn't have a specific variant
+/// for, attaching the canonical ROCm message resolved via
+/// [`rsmi_status_message`].
+pub fn rsmi_try(lib: &RsmiLib, code: rsmi_status_t, name: &'static str) -> Result<(), RsmiError> {
     #[allow(non_upper_case_globals)]
     match code {
         rsmi_status_t_RSMI_STATUS_SUCCESS => Ok(()),
@@ -77,13 +111,90 @@ pub fn rsmi_try(code: rsmi_status_t) -> Result<(), RsmiError> {
         rsmi_status_t_RSMI_STATUS_BUSY => Err(RsmiError::Busy),
         rsmi_status_t_RSMI_STATUS_REFCOUNT_OVERFLOW => Err(RsmiError::RefcountOverflow),
         rsmi_status_t_RSMI_STATUS_UNKNOWN_ERROR => Err(RsmiError::Unknown),
-        _ => Err(RsmiError::Unexpected(code))
+        _ => Err(RsmiError::Unexpected { name, code, message: rsmi_status_message(lib, code) })
     }
 }
 
-pub fn rsmi_sym<T: Clone>(res: &Result<T, libloading::Error>) -> Result<T, RsmiError> {
+/// Resolve the canonical ROCm message string for a status code via
+/// `rsmi_status_string`, returning `None` if the symbol could not be
+/// loaded or didn't return successfully.
+#[doc(alias = "rsmi_status_string")]
+fn rsmi_status_message(lib: &RsmiLib, code: rsmi_status_t) -> Option<String> {
+    let sym = lib.rsmi_status_string.as_ref().ok()?;
+    let mut message: *const std::os::raw::c_char = std::ptr::null();
+    #[allow(non_upper_case_globals)]
+    unsafe {
+        if sym(code, &mut message) != rsmi_status_t_RSMI_STATUS_SUCCESS || message.is_null() {
+            return None;
+        }
+        CStr::from_ptr(message).to_str().ok().map(str::to_string)
+    }
+}
+
+pub fn rsmi_sym<T: Clone>(res: &Result<T, libloading::Error>, name: &'static str) -> Result<T, RsmiError> {
     match res {
         Ok(t) => Ok(t.clone()),
-        Err(e) => Err(RsmiError::FailedToLoadSymbol(e.to_string()))
+        Err(e) => Err(RsmiError::FailedToLoadSymbol { name, message: e.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RsmiError;
+
+    /// Every non-libloading, non-hotplug variant, constructed with
+    /// placeholder data where it carries fields, so `Display` is exercised
+    /// for each arm and none of them panic or render empty.
+    fn variants() -> Vec<RsmiError> {
+        vec![
+            RsmiError::FailedToLoadSymbol { name: "rsmi_init", message: "symbol not found".to_string() },
+            RsmiError::InvalidUtf8,
+            RsmiError::InvalidArgs,
+            RsmiError::NotSupported,
+            RsmiError::File,
+            RsmiError::Permission,
+            RsmiError::OutOfResources,
+            RsmiError::Internal,
+            RsmiError::InputOutOfBounds,
+            RsmiError::Init,
+            RsmiError::NotYetImplemented,
+            RsmiError::NotFound,
+            RsmiError::InsufficientSize,
+            RsmiError::Interrupt,
+            RsmiError::UnexpectedSize,
+            RsmiError::NoData,
+            RsmiError::UnexpectedData,
+            RsmiError::Busy,
+            RsmiError::RefcountOverflow,
+            RsmiError::Unknown,
+            RsmiError::Unexpected { name: "rsmi_dev_id_get", code: 42, message: Some("some status".to_string()) },
+            RsmiError::Unexpected { name: "rsmi_dev_id_get", code: 42, message: None },
+        ]
+    }
+
+    #[test]
+    fn displays_every_variant_on_a_single_line_without_panicking() {
+        for err in variants() {
+            let message = err.to_string();
+            assert!(!message.is_empty());
+            assert!(!message.contains('\n'), "message should not wrap onto multiple lines: {message:?}");
+        }
+    }
+
+    #[test]
+    fn unexpected_display_includes_name_code_and_resolved_message_when_present() {
+        let err = RsmiError::Unexpected { name: "rsmi_dev_id_get", code: 42, message: Some("some status".to_string()) };
+        let message = err.to_string();
+        assert!(message.contains("rsmi_dev_id_get"));
+        assert!(message.contains("42"));
+        assert!(message.contains("some status"));
+    }
+
+    #[test]
+    fn unexpected_display_omits_parens_when_message_is_unresolved() {
+        let err = RsmiError::Unexpected { name: "rsmi_dev_id_get", code: 42, message: None };
+        let message = err.to_string();
+        assert!(message.contains("rsmi_dev_id_get"));
+        assert!(!message.contains('('));
     }
 }
@@ -0,0 +1,177 @@
+//! Optional GPU hotplug / presence-change monitoring, backed by udev.
+//!
+//! ROCm SMI's device list only reflects what was present at `rsmi_init`
+//! time: it has no notion of a GPU being added, removed, or rebound after
+//! that point, and there is no call that makes it rescan. This module lets
+//! long-running daemons learn about those changes by watching udev for
+//! `drm`/`pci` subsystem events.
+//!
+//! Because of that fixed device table, a *removed* GPU is still resolved
+//! back to the monitor index it used to occupy (via [`Device::pci_id`]),
+//! but a newly *added* GPU cannot be: it is never visible through
+//! [`Rsmi::devices`] until the `Rsmi` handle itself is re-initialized, so
+//! [`HotplugEvent::Added`] only carries its raw PCI bus id instead.
+//!
+//! Enabled by the `hotplug` feature.
+
+use std::os::unix::io::AsRawFd;
+
+use crate::device::Device;
+use crate::error::RsmiError;
+use crate::Rsmi;
+
+/// A GPU presence-change event, yielded by [`HotplugWatcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotplugEvent {
+    /// A GPU was added, identified by its PCI bus-device-function id
+    /// (BDFID, in the same encoding as [`Device::pci_id`]). ROCm SMI has no
+    /// way to rescan its monitor device table after `rsmi_init`, so the new
+    /// GPU cannot be resolved to a monitor index here; callers that need one
+    /// must re-initialize `Rsmi` and re-enumerate [`Rsmi::devices`].
+    Added { bdfid: u64 },
+    /// A GPU was removed; `device_index` is the monitor index it used to
+    /// occupy, which callers should stop using from this point on.
+    Removed { device_index: u32 },
+}
+
+/// Blocking iterator over GPU [`HotplugEvent`]s, returned by
+/// [`Rsmi::watch_hotplug`].
+pub struct HotplugWatcher<'a> {
+    rsmi: &'a Rsmi,
+    socket: udev::MonitorSocket,
+}
+
+impl<'a> HotplugWatcher<'a> {
+
+    pub(crate) fn new(rsmi: &'a Rsmi) -> Result<Self, RsmiError> {
+        let socket = udev::MonitorBuilder::new()
+            .map_err(RsmiError::HotplugError)?
+            .match_subsystem("drm")
+            .map_err(RsmiError::HotplugError)?
+            .match_subsystem("pci")
+            .map_err(RsmiError::HotplugError)?
+            .listen()
+            .map_err(RsmiError::HotplugError)?;
+        Ok(HotplugWatcher { rsmi, socket })
+    }
+
+    /// Resolve a udev device event back to the monitor index ROCm SMI knows
+    /// it by, matching on the `rsmi_dev_pci_id_get` BDFID. Only meaningful
+    /// for a GPU ROCm SMI already knew about at `rsmi_init` time.
+    fn device_index_of(&self, udev_device: &udev::Device) -> Option<u32> {
+        let bdfid = bdfid_of(udev_device)?;
+        self.rsmi.devices().find(|dev| matches_bdfid(dev, bdfid)).map(|dev| dev.index())
+    }
+}
+
+impl<'a> Iterator for HotplugWatcher<'a> {
+    type Item = HotplugEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.socket.iter().next() {
+                let action = event.event_type();
+                let udev_device = event.device();
+
+                let hotplug_event = match action {
+                    // The new GPU is never in `rsmi.devices()` (ROCm SMI
+                    // doesn't rescan after `rsmi_init`), so report its raw
+                    // bus id instead of attempting (and failing) to resolve
+                    // a monitor index.
+                    udev::EventType::Add => {
+                        bdfid_of(&udev_device).map(|bdfid| HotplugEvent::Added { bdfid })
+                    }
+                    udev::EventType::Remove => {
+                        self.device_index_of(&udev_device).map(|device_index| HotplugEvent::Removed { device_index })
+                    }
+                    _ => None,
+                };
+
+                if let Some(hotplug_event) = hotplug_event {
+                    return Some(hotplug_event);
+                }
+
+                // Event didn't resolve to a known GPU (e.g. a "change" action,
+                // or a non-GPU `drm`/`pci` device): keep waiting.
+                continue;
+            }
+
+            // No event queued yet: block until the monitor's socket is readable.
+            wait_until_readable(self.socket.as_raw_fd());
+        }
+    }
+}
+
+fn matches_bdfid(dev: &Device, bdfid: u64) -> bool {
+    dev.pci_id().map(|dev_bdfid| dev_bdfid == bdfid).unwrap_or(false)
+}
+
+/// Read and parse a udev device's `PCI_SLOT_NAME` property into a BDFID.
+fn bdfid_of(udev_device: &udev::Device) -> Option<u64> {
+    let pci_slot = udev_device.property_value("PCI_SLOT_NAME")?.to_str()?;
+    parse_pci_slot_name(pci_slot)
+}
+
+/// Parse a udev `PCI_SLOT_NAME` property (`"dddd:bb:dd.f"`) into the BDFID
+/// layout returned by `rsmi_dev_pci_id_get`: domain in bits 32-47, bus in
+/// bits 8-15, device in bits 3-7 and function in bits 0-2.
+fn parse_pci_slot_name(pci_slot: &str) -> Option<u64> {
+    let (domain, rest) = pci_slot.split_once(':')?;
+    let (bus, rest) = rest.split_once(':')?;
+    let (device, function) = rest.split_once('.')?;
+
+    let domain = u64::from_str_radix(domain, 16).ok()?;
+    let bus = u64::from_str_radix(bus, 16).ok()?;
+    let device = u64::from_str_radix(device, 16).ok()?;
+    let function = u64::from_str_radix(function, 16).ok()?;
+
+    Some((domain << 32) | (bus << 8) | (device << 3) | function)
+}
+
+/// Block the calling thread until `fd` becomes readable.
+fn wait_until_readable(fd: std::os::unix::io::RawFd) {
+    let mut poll_fd = libc::pollfd { fd, events: libc::POLLIN, revents: 0 };
+    // SAFETY: `poll_fd` is a single, correctly initialized `pollfd` and we
+    // pass its own length; a negative (error) or zero (timeout, unreachable
+    // since we pass no timeout) return is simply retried on the next loop
+    // iteration by the caller.
+    unsafe {
+        libc::poll(&mut poll_fd, 1, -1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_pci_slot_name;
+
+    #[test]
+    fn parses_slot_name_fields_into_their_bit_ranges() {
+        let bdfid = parse_pci_slot_name("0000:0a:1f.3").unwrap();
+        assert_eq!(bdfid, (0x0000 << 32) | (0x0a << 8) | (0x1f << 3) | 0x3);
+    }
+
+    #[test]
+    fn parses_nonzero_domain() {
+        let bdfid = parse_pci_slot_name("0001:00:00.0").unwrap();
+        assert_eq!(bdfid, 0x1 << 32);
+    }
+
+    #[test]
+    fn round_trips_every_function_on_a_device() {
+        for function in 0..8u64 {
+            let pci_slot = format!("0000:65:00.{function}");
+            let bdfid = parse_pci_slot_name(&pci_slot).unwrap();
+            assert_eq!(bdfid & 0x7, function);
+            assert_eq!((bdfid >> 3) & 0x1f, 0);
+            assert_eq!((bdfid >> 8) & 0xff, 0x65);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_slot_names() {
+        assert_eq!(parse_pci_slot_name(""), None);
+        assert_eq!(parse_pci_slot_name("0000:0a:1f"), None);
+        assert_eq!(parse_pci_slot_name("gggg:0a:1f.0"), None);
+        assert_eq!(parse_pci_slot_name("0000:0a:1f.g"), None);
+    }
+}
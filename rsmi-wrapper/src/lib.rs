@@ -1,7 +1,12 @@
 pub mod error;
 pub mod flags;
+pub mod device;
+pub mod metrics;
+pub mod sampler;
+#[cfg(feature = "hotplug")]
+pub mod hotplug;
 
-use std::ffi::{OsStr, CString, CStr};
+use std::ffi::OsStr;
 use std::mem::ManuallyDrop;
 
 // Here we re-export the sys crate.
@@ -11,6 +16,12 @@ use rsmi_wrapper_sys::RsmiLib;
 // Local uses
 use crate::error::{RsmiError, rsmi_try, rsmi_sym};
 use crate::flags::InitFlags;
+pub use crate::device::Device;
+use crate::device::Devices;
+pub use crate::metrics::{TemperatureSensor, TemperatureMetric, ClockDomain, MemoryKind};
+pub use crate::sampler::{Sampler, SamplerHandle, SamplerOutput, SampledMetric, SampledValue, Sample};
+#[cfg(feature = "hotplug")]
+pub use crate::hotplug::{HotplugEvent, HotplugWatcher};
 
 
 #[cfg(not(target_os = "linux"))]
@@ -23,7 +34,7 @@ const LIB_PATH: &str = "librocm_smi64.so";
 /// Safe wrapper around ROCm SMI library, documented at
 /// [here](https://raw.githubusercontent.com/RadeonOpenCompute/rocm_smi_lib/master/rocm_smi/docs/ROCm_SMI_Manual.pdf).
 pub struct Rsmi {
-    lib: ManuallyDrop<RsmiLib>
+    pub(crate) lib: ManuallyDrop<RsmiLib>
 }
 
 impl Rsmi {
@@ -31,8 +42,8 @@ impl Rsmi {
     pub fn init_with_path_and_flags<P: AsRef<OsStr>>(path: P, flags: InitFlags) -> Result<Self, RsmiError> {
         let lib = unsafe {
             let lib = RsmiLib::new(path)?;
-            let sym = rsmi_sym(&lib.rsmi_init)?;
-            rsmi_try(sym(flags.bits()))?;
+            let sym = rsmi_sym(&lib.rsmi_init, "rsmi_init")?;
+            rsmi_try(&lib, sym(flags.bits()), "rsmi_init")?;
             ManuallyDrop::new(lib)
         };
         Ok(Rsmi { lib })
@@ -50,8 +61,8 @@ impl Rsmi {
     pub fn shutdown(mut self) -> Result<(), RsmiError> {
         
         unsafe {
-            let sym = rsmi_sym(&self.lib.rsmi_shut_down)?;
-            rsmi_try(sym())?;
+            let sym = rsmi_sym(&self.lib.rsmi_shut_down, "rsmi_shut_down")?;
+            rsmi_try(&self.lib, sym(), "rsmi_shut_down")?;
         }
 
         unsafe {
@@ -72,88 +83,36 @@ impl Rsmi {
     /// referenced by the index which can be between 0 and `count - 1`.
     #[doc(alias = "rsmi_num_monitor_devices")]
     pub fn monitor_devices_count(&self) -> Result<u32, RsmiError> {
-        let sym = rsmi_sym(&self.lib.rsmi_num_monitor_devices)?;
+        let sym = rsmi_sym(&self.lib.rsmi_num_monitor_devices, "rsmi_num_monitor_devices")?;
         let mut count = 0;
-        unsafe { 
-            rsmi_try(sym(&mut count))?; 
-        }
-        Ok(count)
-    }
-
-    /// Get the device id associated with the device with provided device index.
-    #[doc(alias = "rsmi_dev_id_get")]
-    pub fn get_device_id(&self, device_index: u32) -> Result<u16, RsmiError> {
-        let sym = rsmi_sym(&self.lib.rsmi_dev_id_get)?;
-        let mut count = 0;
-        unsafe { 
-            rsmi_try(sym(device_index, &mut count))?; 
-        }
-        Ok(count)
-    }
-
-    /// Get the SKU for a desired device associated with the device with provided device index.
-    #[doc(alias = "rsmi_dev_sku_get")]
-    pub fn get_device_sku(&self, device_index: u32) -> Result<i8, RsmiError> {
-        let sym = rsmi_sym(&self.lib.rsmi_dev_sku_get)?;
-        let mut sku = 0;
-        unsafe { 
-            rsmi_try(sym(device_index, &mut sku))?; 
-        }
-        Ok(sku)
-    }
-
-    /// Get the device vendor id associated with the device with provided device index
-    #[doc(alias = "rsmi_dev_vendor_id_get")]
-    pub fn get_device_vendor_id(&self, device_index: u32) -> Result<u16, RsmiError> {
-        let sym = rsmi_sym(&self.lib.rsmi_dev_vendor_id_get)?;
-        let mut vendor_id = 0;
-        unsafe { 
-            rsmi_try(sym(device_index, &mut vendor_id))?; 
-        }
-        Ok(vendor_id)
-    }
-
-    fn get_device_string<S: From<u16>>(&self, device_index: u32, sym: unsafe extern "C" fn(u32, *mut i8, S) -> u32) -> Result<String, RsmiError> {
-        const BUFFER_LEN: u16 = 256;
-        let mut buffer = [0i8; BUFFER_LEN as usize];
         unsafe {
-            rsmi_try(sym(device_index, buffer.as_mut_ptr(), BUFFER_LEN.into()))?;
-            CStr::from_ptr(buffer.as_mut_ptr()).to_str().map_err(|_| RsmiError::InvalidUtf8).map(str::to_string)
+            rsmi_try(&self.lib, sym(&mut count), "rsmi_num_monitor_devices")?;
         }
+        Ok(count)
     }
 
-    /// Get the name string of a gpu device.
-    #[doc(alias = "rsmi_dev_name_get")]
-    pub fn get_device_name(&self, device_index: u32) -> Result<String, RsmiError> {
-        self.get_device_string(device_index, rsmi_sym(&self.lib.rsmi_dev_name_get)?)
-    }
-
-    /// Get the brand string of a gpu device.
-    #[doc(alias = "rsmi_dev_brand_get")]
-    pub fn get_device_brand(&self, device_index: u32) -> Result<String, RsmiError> {
-        self.get_device_string(device_index, rsmi_sym(&self.lib.rsmi_dev_brand_get)?)
-    }
-
-    /// Get the name string for a give vendor ID.
-    #[doc(alias = "rsmi_dev_vendor_name_get")]
-    pub fn get_device_vendor_name(&self, device_index: u32) -> Result<String, RsmiError> {
-        self.get_device_string(device_index, rsmi_sym(&self.lib.rsmi_dev_vendor_name_get)?)
-    }
-
-    /// Get the vram vendor string of a gpu device.
-    #[doc(alias = "rsmi_dev_vram_vendor_get")]
-    pub fn get_device_vram_vendor_name(&self, device_index: u32) -> Result<String, RsmiError> {
-        self.get_device_string(device_index, rsmi_sym(&self.lib.rsmi_dev_vendor_name_get)?)
+    /// Iterate over all devices that have monitor information.
+    ///
+    /// This is the idiomatic replacement for manually looping over
+    /// `0..monitor_devices_count()?` and indexing the per-device getters:
+    /// each yielded [`Device`] already knows its index and exposes that
+    /// same information through methods like `dev.name()` or `dev.id()`.
+    ///
+    /// If the device count cannot be queried, the iterator yields no devices.
+    #[doc(alias = "rsmi_num_monitor_devices")]
+    pub fn devices(&self) -> impl Iterator<Item = Device<'_>> {
+        let count = self.monitor_devices_count().unwrap_or(0);
+        Devices { rsmi: self, index: 0, count }
     }
 
-    /// Get the vram vendor string of a gpu device.
-    #[doc(alias = "rsmi_dev_serial_number_get")]
-    pub fn get_device_serial_number(&self, device_index: u32) -> Result<String, RsmiError> {
-        self.get_device_string(device_index, rsmi_sym(&self.lib.rsmi_dev_serial_number_get)?)
+    /// Open a udev monitor that reports GPU presence changes (added/removed)
+    /// as they happen, for daemons that need to stay correct across a GPU
+    /// reset or rebind. Requires the `hotplug` feature.
+    #[cfg(feature = "hotplug")]
+    pub fn watch_hotplug(&self) -> Result<crate::hotplug::HotplugWatcher<'_>, RsmiError> {
+        crate::hotplug::HotplugWatcher::new(self)
     }
 
-    pub fn get_device_subsystem
-
 }
 
 impl Drop for Rsmi {
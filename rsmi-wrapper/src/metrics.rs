@@ -0,0 +1,285 @@
+//! Hardware-monitoring surface exposed on [`Device`]: temperature, fan,
+//! power, clock and memory-usage readings.
+//!
+//! Every getter here returns `Ok(None)` rather than an error when the
+//! underlying ROCm SMI call reports `RSMI_STATUS_NOT_SUPPORTED`, so callers
+//! can probe capability per device instead of handling a hard error for
+//! every sensor that a given GPU happens not to expose.
+
+use rsmi_wrapper_sys::*;
+
+use crate::device::Device;
+use crate::error::{RsmiError, rsmi_sym, rsmi_try};
+
+
+/// Which thermal sensor a [`Device::temperature`] reading comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureSensor {
+    Edge,
+    Junction,
+    Memory,
+}
+
+impl TemperatureSensor {
+    fn as_raw(self) -> u32 {
+        match self {
+            Self::Edge => rsmi_temperature_type_t_RSMI_TEMP_TYPE_EDGE,
+            Self::Junction => rsmi_temperature_type_t_RSMI_TEMP_TYPE_JUNCTION,
+            Self::Memory => rsmi_temperature_type_t_RSMI_TEMP_TYPE_MEMORY,
+        }
+    }
+}
+
+/// Which kind of temperature value to read for a given [`TemperatureSensor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureMetric {
+    Current,
+    Max,
+    Critical,
+}
+
+impl TemperatureMetric {
+    fn as_raw(self) -> rsmi_temperature_metric_t {
+        match self {
+            Self::Current => rsmi_temperature_metric_t_RSMI_TEMP_CURRENT,
+            Self::Max => rsmi_temperature_metric_t_RSMI_TEMP_MAX,
+            Self::Critical => rsmi_temperature_metric_t_RSMI_TEMP_CRITICAL,
+        }
+    }
+}
+
+/// A clock domain exposed by [`Device::clock_frequency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockDomain {
+    System,
+    Memory,
+    Dcef,
+    Soc,
+    Fabric,
+}
+
+impl ClockDomain {
+    fn as_raw(self) -> rsmi_clk_type_t {
+        match self {
+            Self::System => rsmi_clk_type_t_RSMI_CLK_TYPE_SYS,
+            Self::Memory => rsmi_clk_type_t_RSMI_CLK_TYPE_MEM,
+            Self::Dcef => rsmi_clk_type_t_RSMI_CLK_TYPE_DCEF,
+            Self::Soc => rsmi_clk_type_t_RSMI_CLK_TYPE_SOC,
+            Self::Fabric => rsmi_clk_type_t_RSMI_CLK_TYPE_DF,
+        }
+    }
+}
+
+/// A memory pool exposed by [`Device::memory_total`] and [`Device::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    Vram,
+    VisibleVram,
+    Gtt,
+}
+
+impl MemoryKind {
+    fn as_raw(self) -> rsmi_memory_type_t {
+        match self {
+            Self::Vram => rsmi_memory_type_t_RSMI_MEM_TYPE_VRAM,
+            Self::VisibleVram => rsmi_memory_type_t_RSMI_MEM_TYPE_VIS_VRAM,
+            Self::Gtt => rsmi_memory_type_t_RSMI_MEM_TYPE_GTT,
+        }
+    }
+}
+
+/// Turns a `NotSupported` error into `Ok(None)`, leaving every other
+/// outcome (including success) untouched.
+fn optional<T>(res: Result<T, RsmiError>) -> Result<Option<T>, RsmiError> {
+    match res {
+        Ok(v) => Ok(Some(v)),
+        Err(RsmiError::NotSupported) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+impl<'a> Device<'a> {
+
+    /// Get a temperature reading, in degrees Celsius, from the given sensor.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_temp_metric_get")]
+    pub fn temperature(&self, sensor: TemperatureSensor, metric: TemperatureMetric) -> Result<Option<f64>, RsmiError> {
+        optional(self.temperature_millidegrees(sensor, metric))
+            .map(|v| v.map(|milli| milli as f64 / 1000.0))
+    }
+
+    fn temperature_millidegrees(&self, sensor: TemperatureSensor, metric: TemperatureMetric) -> Result<i64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_temp_metric_get, "rsmi_dev_temp_metric_get")?;
+        let mut milli = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), sensor.as_raw(), metric.as_raw(), &mut milli), "rsmi_dev_temp_metric_get")?;
+        }
+        Ok(milli)
+    }
+
+    /// Get the current fan speed, as a relative value between `0` and
+    /// [`Device::fan_speed_max`].
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_fan_speed_get")]
+    pub fn fan_speed(&self) -> Result<Option<i64>, RsmiError> {
+        optional(self.fan_speed_raw())
+    }
+
+    fn fan_speed_raw(&self) -> Result<i64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_fan_speed_get, "rsmi_dev_fan_speed_get")?;
+        let mut speed = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), 0, &mut speed), "rsmi_dev_fan_speed_get")?;
+        }
+        Ok(speed)
+    }
+
+    /// Get the current fan speed, in RPM.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_fan_rpms_get")]
+    pub fn fan_rpms(&self) -> Result<Option<i64>, RsmiError> {
+        optional(self.fan_rpms_raw())
+    }
+
+    fn fan_rpms_raw(&self) -> Result<i64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_fan_rpms_get, "rsmi_dev_fan_rpms_get")?;
+        let mut rpms = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), 0, &mut rpms), "rsmi_dev_fan_rpms_get")?;
+        }
+        Ok(rpms)
+    }
+
+    /// Get the maximum fan speed, as a relative value on the same scale as
+    /// [`Device::fan_speed`].
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_fan_speed_max_get")]
+    pub fn fan_speed_max(&self) -> Result<Option<u64>, RsmiError> {
+        optional(self.fan_speed_max_raw())
+    }
+
+    fn fan_speed_max_raw(&self) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_fan_speed_max_get, "rsmi_dev_fan_speed_max_get")?;
+        let mut max_speed = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), 0, &mut max_speed), "rsmi_dev_fan_speed_max_get")?;
+        }
+        Ok(max_speed)
+    }
+
+    /// Get the average power draw, in watts.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_power_ave_get")]
+    pub fn power_average(&self) -> Result<Option<f64>, RsmiError> {
+        optional(self.power_average_micro_watts())
+            .map(|v| v.map(|micro_watts| micro_watts as f64 / 1_000_000.0))
+    }
+
+    fn power_average_micro_watts(&self) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_power_ave_get, "rsmi_dev_power_ave_get")?;
+        let mut micro_watts = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), 0, &mut micro_watts), "rsmi_dev_power_ave_get")?;
+        }
+        Ok(micro_watts)
+    }
+
+    /// Get the power cap, in watts, above which the device will throttle
+    /// itself down.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_power_cap_get")]
+    pub fn power_cap(&self) -> Result<Option<f64>, RsmiError> {
+        optional(self.power_cap_micro_watts())
+            .map(|v| v.map(|micro_watts| micro_watts as f64 / 1_000_000.0))
+    }
+
+    fn power_cap_micro_watts(&self) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_power_cap_get, "rsmi_dev_power_cap_get")?;
+        let mut micro_watts = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), 0, &mut micro_watts), "rsmi_dev_power_cap_get")?;
+        }
+        Ok(micro_watts)
+    }
+
+    /// Get the current frequency of the given clock domain, in Hz.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_gpu_clk_freq_get")]
+    pub fn clock_frequency(&self, domain: ClockDomain) -> Result<Option<u64>, RsmiError> {
+        // `current` is an index into `frequency` reported by the driver; fold
+        // an out-of-range value into `Ok(None)` rather than panicking.
+        Ok(optional(self.clock_frequency_raw(domain))?
+            .and_then(|freqs| freqs.frequency.get(freqs.current as usize).copied()))
+    }
+
+    fn clock_frequency_raw(&self, domain: ClockDomain) -> Result<rsmi_frequencies_t, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_gpu_clk_freq_get, "rsmi_dev_gpu_clk_freq_get")?;
+        // SAFETY: `rsmi_frequencies_t` is a plain-old-data struct of
+        // integers, zero is a valid (if meaningless) value for every field
+        // until the call below fills it in.
+        let mut freqs: rsmi_frequencies_t = unsafe { std::mem::zeroed() };
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), domain.as_raw(), &mut freqs), "rsmi_dev_gpu_clk_freq_get")?;
+        }
+        Ok(freqs)
+    }
+
+    /// Get the current GPU busy percentage, between `0` and `100`.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_busy_percent_get")]
+    pub fn busy_percent(&self) -> Result<Option<u32>, RsmiError> {
+        optional(self.busy_percent_raw())
+    }
+
+    fn busy_percent_raw(&self) -> Result<u32, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_busy_percent_get, "rsmi_dev_busy_percent_get")?;
+        let mut busy_percent = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), &mut busy_percent), "rsmi_dev_busy_percent_get")?;
+        }
+        Ok(busy_percent)
+    }
+
+    /// Get the total amount of memory, in bytes, for the given memory pool.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_memory_total_get")]
+    pub fn memory_total(&self, kind: MemoryKind) -> Result<Option<u64>, RsmiError> {
+        optional(self.memory_total_raw(kind))
+    }
+
+    fn memory_total_raw(&self, kind: MemoryKind) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_memory_total_get, "rsmi_dev_memory_total_get")?;
+        let mut total = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), kind.as_raw(), &mut total), "rsmi_dev_memory_total_get")?;
+        }
+        Ok(total)
+    }
+
+    /// Get the currently used amount of memory, in bytes, for the given
+    /// memory pool.
+    ///
+    /// Returns `Ok(None)` if this metric is not supported by this device.
+    #[doc(alias = "rsmi_dev_memory_usage_get")]
+    pub fn memory_usage(&self, kind: MemoryKind) -> Result<Option<u64>, RsmiError> {
+        optional(self.memory_usage_raw(kind))
+    }
+
+    fn memory_usage_raw(&self, kind: MemoryKind) -> Result<u64, RsmiError> {
+        let sym = rsmi_sym(&self.rsmi().lib.rsmi_dev_memory_usage_get, "rsmi_dev_memory_usage_get")?;
+        let mut used = 0;
+        unsafe {
+            rsmi_try(&self.rsmi().lib, sym(self.index(), kind.as_raw(), &mut used), "rsmi_dev_memory_usage_get")?;
+        }
+        Ok(used)
+    }
+}
@@ -0,0 +1,203 @@
+//! Background periodic sampler that polls [`Device`] metrics on a fixed
+//! cadence, producing a timestamped metric time-series without callers
+//! having to write their own thread/loop boilerplate.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::device::Device;
+use crate::metrics::{ClockDomain, MemoryKind, TemperatureMetric, TemperatureSensor};
+use crate::Rsmi;
+
+// `Sampler` moves an `Arc<Rsmi>` onto a background thread while the caller
+// may keep other clones of the same `Arc` alive on its own thread(s). This
+// is only sound because every `Rsmi`/`Device` getter only reads already
+// resolved function pointers and calls into ROCm SMI, never mutates shared
+// state; the assertion below enforces that invariant at compile time,
+// rather than leaving it as a comment that can silently go stale.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Rsmi>();
+};
+
+/// A single metric to poll for every watched device, requested through
+/// [`Sampler::start`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampledMetric {
+    Temperature(TemperatureSensor, TemperatureMetric),
+    FanRpms,
+    PowerAverage,
+    ClockFrequency(ClockDomain),
+    BusyPercent,
+    MemoryUsage(MemoryKind),
+}
+
+impl SampledMetric {
+    fn poll(self, dev: &Device) -> Result<SampledValue, crate::error::RsmiError> {
+        Ok(match self {
+            Self::Temperature(sensor, metric) => SampledValue::Temperature(dev.temperature(sensor, metric)?),
+            Self::FanRpms => SampledValue::FanRpms(dev.fan_rpms()?),
+            Self::PowerAverage => SampledValue::PowerAverage(dev.power_average()?),
+            Self::ClockFrequency(domain) => SampledValue::ClockFrequency(dev.clock_frequency(domain)?),
+            Self::BusyPercent => SampledValue::BusyPercent(dev.busy_percent()?),
+            Self::MemoryUsage(kind) => SampledValue::MemoryUsage(dev.memory_usage(kind)?),
+        })
+    }
+}
+
+/// The value produced by polling a [`SampledMetric`], mirroring the
+/// SI-normalized return type of the corresponding `Device` getter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampledValue {
+    Temperature(Option<f64>),
+    FanRpms(Option<i64>),
+    PowerAverage(Option<f64>),
+    ClockFrequency(Option<u64>),
+    BusyPercent(Option<u32>),
+    MemoryUsage(Option<u64>),
+}
+
+/// One timestamped reading of a single [`SampledMetric`] for a single device.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub at: Instant,
+    pub device_index: u32,
+    pub metric: SampledMetric,
+    pub value: SampledValue,
+}
+
+/// Where a [`Sampler`] delivers the samples it produces.
+pub enum SamplerOutput {
+    /// Keep the last `capacity` samples in memory, dropping the oldest on
+    /// overflow; read back through [`SamplerHandle::latest`] and
+    /// [`SamplerHandle::drain`].
+    Buffer(usize),
+    /// Forward every sample to an `mpsc::Sender`, e.g. to feed a logging
+    /// or dashboard thread.
+    Channel(Sender<Sample>),
+    /// Forward every sample to a user-supplied callback, called from the
+    /// sampler's background thread.
+    Callback(Box<dyn Fn(Sample) + Send + 'static>),
+}
+
+/// Background periodic sampler, started with [`Sampler::start`].
+pub struct Sampler;
+
+impl Sampler {
+
+    /// Spawn a background thread that polls `metrics` for every device in
+    /// `devices` every `interval`, delivering samples through `output`.
+    ///
+    /// The background thread itself owns the `Arc<Rsmi>` clone passed in
+    /// here (the returned [`SamplerHandle`] only holds the stop flag, the
+    /// join handle and the sample buffer), so `rsmi_shut_down` cannot run until that thread has
+    /// actually exited: [`Rsmi::shutdown`] takes `self` by value and so
+    /// cannot be called while this (or any other) `Arc` clone is alive, and
+    /// the `Drop` impl only runs once the last clone, including the
+    /// thread's, is gone.
+    pub fn start(
+        rsmi: Arc<Rsmi>,
+        devices: Vec<u32>,
+        metrics: Vec<SampledMetric>,
+        interval: Duration,
+        output: SamplerOutput,
+    ) -> SamplerHandle {
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let buffer = match &output {
+            SamplerOutput::Buffer(capacity) => Some(Arc::new(Mutex::new(VecDeque::with_capacity(*capacity)))),
+            _ => None,
+        };
+
+        let thread_stop = Arc::clone(&stop);
+        let thread_buffer = buffer.clone();
+
+        let thread = std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+
+                for &device_index in &devices {
+                    let dev = Device::new(&rsmi, device_index);
+                    for &metric in &metrics {
+                        let value = match metric.poll(&dev) {
+                            Ok(value) => value,
+                            // A hard error (as opposed to `NotSupported`, which
+                            // is already folded into `Ok(None)` by `Device`)
+                            // just means this tick's reading is skipped.
+                            Err(_) => continue,
+                        };
+                        let sample = Sample { at: Instant::now(), device_index, metric, value };
+
+                        match &output {
+                            SamplerOutput::Buffer(capacity) => {
+                                let mut buffer = thread_buffer.as_ref().unwrap().lock().unwrap();
+                                if buffer.len() >= *capacity {
+                                    buffer.pop_front();
+                                }
+                                if *capacity > 0 {
+                                    buffer.push_back(sample);
+                                }
+                            }
+                            SamplerOutput::Channel(sender) => {
+                                // If the receiver was dropped there is nothing more to deliver to.
+                                let _ = sender.send(sample);
+                            }
+                            SamplerOutput::Callback(callback) => callback(sample),
+                        }
+                    }
+                }
+
+                std::thread::sleep(interval);
+            }
+        });
+
+        SamplerHandle { stop, thread: Some(thread), buffer }
+    }
+}
+
+/// Handle to a running [`Sampler`], returned by [`Sampler::start`].
+pub struct SamplerHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    buffer: Option<Arc<Mutex<VecDeque<Sample>>>>,
+}
+
+impl SamplerHandle {
+
+    /// The most recent sample, if the sampler was started with
+    /// [`SamplerOutput::Buffer`] and has produced at least one sample.
+    pub fn latest(&self) -> Option<Sample> {
+        self.buffer.as_ref()?.lock().unwrap().back().copied()
+    }
+
+    /// Take every sample currently held in the buffer, leaving it empty.
+    ///
+    /// Returns an empty `Vec` if the sampler was not started with
+    /// [`SamplerOutput::Buffer`].
+    pub fn drain(&self) -> Vec<Sample> {
+        match &self.buffer {
+            Some(buffer) => buffer.lock().unwrap().drain(..).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Stop the background thread and wait for it to exit.
+    pub fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for SamplerHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}